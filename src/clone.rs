@@ -7,10 +7,21 @@
 //! enabling calls to world.clone() to work as you would expect.  Without a registry
 //! initialized on World creation, world.clone() WILL panic.
 
-use crate::alloc::vec::Vec;
-use crate::{Archetype, ColumnBatchBuilder, ColumnBatchType, Component};
 use core::any::type_name;
 use core::any::TypeId;
+use core::fmt;
+
+use crate::alloc::sync::Arc;
+use crate::alloc::vec::Vec;
+use crate::{
+    Archetype, ColumnBatchBuilder, ColumnBatchType, Component, Entity, EntityBuilder, EntityRef,
+    World,
+};
+
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// An opaque registry that holds data that helps a World clone itself.
 #[derive(Clone, Default)]
@@ -25,6 +36,141 @@ impl CloneRegistry {
         }
         self
     }
+
+    /// Registers `T` with the registry like [`register`](Self::register), but clones whole
+    /// archetype columns at once via `memcpy` rather than cloning each element individually.
+    ///
+    /// Prefer this over `register` for plain-old-data components: it avoids a clone and push
+    /// per entity, which dominates the cost of cloning large worlds full of cheap
+    /// components like `Position` or `Velocity`.
+    pub fn register_copy<T: Copy + Component>(mut self) -> Self {
+        if !self.0.iter().any(|item| item.type_id == TypeId::of::<T>()) {
+            self.0.push(register_copy::<T>());
+        }
+        self
+    }
+
+    /// Registers `T` like [`register`](Self::register), but additionally rewrites any
+    /// `Entity` handles `T` contains whenever it is cloned via [`World::merge_from`].
+    ///
+    /// `remap` is called once per freshly spawned component with the [`EntityMap`] produced
+    /// by the merge; it should look up and rewrite each `Entity` it holds, leaving handles
+    /// that aren't in the map unchanged since they may point outside the merged set.
+    pub fn register_mapped<T: Clone + Component>(mut self, remap: fn(&mut T, &EntityMap)) -> Self {
+        if !self.0.iter().any(|item| item.type_id == TypeId::of::<T>()) {
+            self.0.push(register_mapped::<T>(remap));
+        }
+        self
+    }
+
+    fn entry_for(&self, type_id: TypeId) -> Option<&CloneEntry> {
+        self.0.iter().find(|item| item.type_id == type_id)
+    }
+
+    /// Checks that every component type present in `archetype` has been `register`ed,
+    /// returning the first offender found otherwise.
+    pub(crate) fn check_archetype(&self, archetype: &Archetype) -> Result<(), TypeUnknownToCloner> {
+        for info in archetype.types() {
+            if self.entry_for(info.id()).is_none() {
+                return Err(TypeUnknownToCloner {
+                    type_id: info.id(),
+                    type_name: info.type_name(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The registry entries for exactly the component types present in `archetype`, in
+    /// `archetype`'s own order.
+    ///
+    /// Iterating the whole registry against every archetype is wrong whenever a `World` has
+    /// more than one archetype shape: an entry for a type `archetype` doesn't have would
+    /// still run `add_values`/`add_type` against it, and the `register`/`register_copy`
+    /// closures panic when a component is missing from the archetype they're handed.
+    fn entries_for<'a>(
+        &'a self,
+        archetype: &'a Archetype,
+    ) -> impl Iterator<Item = &'a CloneEntry> + 'a {
+        archetype.types().iter().map(move |info| {
+            self.entry_for(info.id()).unwrap_or_else(|| {
+                panic!(
+                    "component `{}` is not registered with the CloneRegistry",
+                    info.type_name()
+                )
+            })
+        })
+    }
+
+    pub(crate) fn clone_archetype(&self, batch: &mut ColumnBatchBuilder, archetype: &Archetype) {
+        for entry in self.entries_for(archetype) {
+            (entry.add_values)(batch, archetype);
+        }
+    }
+
+    pub(crate) fn batch_type_for(&self, archetype: &Archetype) -> ColumnBatchType {
+        let mut ty = ColumnBatchType::new();
+        for entry in self.entries_for(archetype) {
+            (entry.add_type)(&mut ty);
+        }
+        ty
+    }
+
+    pub(crate) fn clone_entity(&self, src: EntityRef<'_>, builder: &mut EntityBuilder) {
+        for entry in &self.0 {
+            (entry.add_one)(builder, src);
+        }
+    }
+}
+
+/// Error returned by [`World::try_clone`] when `world` contains a component type that was
+/// never registered with the [`CloneRegistry`] (by `register`, `register_copy` or
+/// `register_mapped`).
+///
+/// This happens when a component was added to the source `World` but the caller forgot to
+/// register it with the `CloneRegistry` used to build that `World`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeUnknownToCloner {
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl TypeUnknownToCloner {
+    /// The [`TypeId`] of the component type that has no registered clone function.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The name of the component type that has no registered clone function, as reported by
+    /// [`core::any::type_name`].
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for TypeUnknownToCloner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component `{}` is not registered with the CloneRegistry",
+            self.type_name
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypeUnknownToCloner {}
+
+/// Maps each entity of a source `World` to the entity it was respawned as in a destination
+/// `World`, as produced by [`World::merge_from`].
+#[derive(Clone, Default)]
+pub struct EntityMap(HashMap<Entity, Entity>);
+
+impl EntityMap {
+    /// Looks up the entity that `old` was respawned as, if `old` was part of the merge.
+    pub fn get(&self, old: Entity) -> Option<Entity> {
+        self.0.get(&old).copied()
+    }
 }
 
 #[derive(Clone)]
@@ -32,6 +178,8 @@ pub(crate) struct CloneEntry {
     pub(crate) type_id: TypeId,
     pub(crate) add_type: fn(&mut ColumnBatchType) -> (),
     pub(crate) add_values: fn(&mut ColumnBatchBuilder, &Archetype) -> (),
+    pub(crate) add_one: fn(&mut EntityBuilder, EntityRef<'_>) -> (),
+    pub(crate) remap: Option<Arc<dyn Fn(&mut World, Entity, &EntityMap)>>,
 }
 fn register<T: Component + Clone>() -> CloneEntry {
     CloneEntry {
@@ -56,5 +204,261 @@ fn register<T: Component + Clone>() -> CloneEntry {
                 }
             }
         },
+        add_one: |builder, entity| {
+            if let Some(value) = entity.get::<T>() {
+                builder.add((*value).clone());
+            }
+        },
+        remap: None,
+    }
+}
+
+fn register_mapped<T: Component + Clone>(remap: fn(&mut T, &EntityMap)) -> CloneEntry {
+    let mut entry = register::<T>();
+    entry.remap = Some(Arc::new(move |world, entity, map| {
+        if let Ok(mut component) = world.get_mut::<T>(entity) {
+            remap(&mut component, map);
+        }
+    }));
+    entry
+}
+
+fn register_copy<T: Component + Copy>() -> CloneEntry {
+    CloneEntry {
+        type_id: TypeId::of::<T>(),
+        add_type: |batch_type| {
+            batch_type.add::<T>();
+        },
+        add_values: |batch, arch| {
+            let mut writer = match batch.writer::<T>() {
+                Some(x) => x,
+                None => panic!("missing from clone {}", type_name::<T>()),
+            };
+
+            let column = match arch.get::<T>() {
+                Some(x) => x,
+                None => panic!("missing from archetype {}", type_name::<T>()),
+            };
+
+            // SAFETY: `writer` is freshly obtained above and empty, so its backing storage
+            // is uninitialized and large enough to hold `archetype.len()` elements, matching
+            // `column`'s length.
+            unsafe {
+                writer.extend_from_slice_copy(&column);
+            }
+        },
+        add_one: |builder, entity| {
+            if let Some(value) = entity.get::<T>() {
+                builder.add(*value);
+            }
+        },
+        remap: None,
+    }
+}
+
+impl World {
+    /// Creates an empty `World` that will use `registry` to answer `Clone`/`try_clone`,
+    /// `merge_from`, and `clone_entity`/`clone_entity_into` calls.
+    pub fn new_with_clone_registry(registry: CloneRegistry) -> World {
+        let mut world = World::new();
+        world.set_clone_registry(registry);
+        world
+    }
+
+    /// Create an exact copy of the `World`, cloning every component via the `CloneRegistry`
+    /// the `World` was created with.
+    ///
+    /// Returns [`TypeUnknownToCloner`] if the `World` contains a component type that was
+    /// never registered with the [`CloneRegistry`]. Use [`Clone::clone`] instead if you'd
+    /// rather panic on that case.
+    pub fn try_clone(&self) -> Result<World, TypeUnknownToCloner> {
+        let registry = self.clone_registry();
+
+        for archetype in self.archetypes() {
+            registry.check_archetype(archetype)?;
+        }
+
+        let mut new_world = World::new();
+        for archetype in self.archetypes() {
+            let mut batch = registry
+                .batch_type_for(archetype)
+                .into_batch(archetype.len());
+            registry.clone_archetype(&mut batch, archetype);
+            let batch = batch.build().expect("all components were registered above");
+            new_world.spawn_column_batch_at(archetype.ids(), batch);
+        }
+
+        Ok(new_world)
+    }
+
+    /// Spawns a copy of every entity in `other` into `self`, cloning components via the
+    /// `CloneRegistry` the way [`try_clone`](Self::try_clone) does.
+    ///
+    /// Unlike `try_clone`, the copies are assigned fresh entity ids rather than preserving
+    /// `other`'s ids, since those may already be taken in `self`. The returned [`EntityMap`]
+    /// records old id -> new id for every merged entity, and is also fed to any
+    /// [`CloneRegistry::register_mapped`] component so it can rewrite `Entity` handles it
+    /// holds to point within the merged set.
+    ///
+    /// Every archetype of `other` is checked against the `CloneRegistry` up front, the same
+    /// way `try_clone` does, and panics before spawning anything into `self` if a component
+    /// type is missing — never partway through, leaving `self` half-merged.
+    pub fn merge_from(&mut self, other: &World) -> EntityMap {
+        let registry = self.clone_registry();
+
+        for archetype in other.archetypes() {
+            registry
+                .check_archetype(archetype)
+                .expect("component missing from CloneRegistry; register it before merging");
+        }
+
+        let mut map = EntityMap::default();
+
+        for archetype in other.archetypes() {
+            let mut batch = registry
+                .batch_type_for(archetype)
+                .into_batch(archetype.len());
+            registry.clone_archetype(&mut batch, archetype);
+            let batch = batch.build().expect("component missing from CloneRegistry");
+            let new_ids = self.spawn_column_batch(batch);
+            for (&old, new) in archetype.ids().iter().zip(new_ids) {
+                map.0.insert(old, new);
+            }
+        }
+
+        for entry in &registry.0 {
+            if let Some(remap) = &entry.remap {
+                for &new_id in map.0.values() {
+                    remap(self, new_id, &map);
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Clones every component of `src` that's registered with the `CloneRegistry`, returning
+    /// an [`EntityBuilder`] ready to [`spawn`](World::spawn) as a new entity.
+    ///
+    /// Only the components actually present on `src` are copied; components registered but
+    /// absent from `src` are simply skipped, the way `EntityRef::get` would skip them.
+    pub fn clone_entity(&self, src: Entity) -> EntityBuilder {
+        let registry = self.clone_registry();
+        let entity_ref = self.entity(src).expect("no such entity");
+        let mut builder = EntityBuilder::new();
+        registry.clone_entity(entity_ref, &mut builder);
+        builder
+    }
+
+    /// Like [`clone_entity`](Self::clone_entity), but spawns the clone directly into `dst`
+    /// and returns its new id.
+    pub fn clone_entity_into(&self, src: Entity, dst: &mut World) -> Entity {
+        let mut builder = self.clone_entity(src);
+        dst.spawn(builder.build())
+    }
+}
+
+impl Clone for World {
+    fn clone(&self) -> Self {
+        self.try_clone()
+            .expect("component missing from CloneRegistry; use World::try_clone to handle this")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Pos(i32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Link(Entity);
+
+    fn remap_link(link: &mut Link, map: &EntityMap) {
+        if let Some(new) = map.get(link.0) {
+            link.0 = new;
+        }
+    }
+
+    #[test]
+    fn merge_from_assigns_fresh_ids_and_remaps_entity_handles() {
+        let registry = CloneRegistry::default()
+            .register::<Pos>()
+            .register_mapped::<Link>(remap_link);
+
+        let mut other = World::new_with_clone_registry(registry.clone());
+        let target = other.spawn((Pos(1),));
+        other.spawn((Link(target),));
+
+        let mut world = World::new_with_clone_registry(registry);
+        // Occupy the destination's first id, which `target` also has in `other`.
+        world.spawn((Pos(0),));
+        let map = world.merge_from(&other);
+
+        // Ids are freshly allocated, not preserved from `other`.
+        let new_target = map.get(target).expect("target entity was merged");
+        assert_ne!(new_target, target);
+
+        let (_, &link) = world
+            .query::<&Link>()
+            .iter()
+            .next()
+            .expect("Link was merged");
+        assert_eq!(link.0, new_target);
+    }
+
+    #[test]
+    fn register_copy_round_trips_through_clone() {
+        let registry = CloneRegistry::default().register_copy::<Pos>();
+        let mut world = World::new_with_clone_registry(registry);
+        world.spawn((Pos(1),));
+        world.spawn((Pos(2),));
+        world.spawn((Pos(3),));
+
+        let cloned = world.clone();
+
+        let mut original: Vec<Pos> = world.query::<&Pos>().iter().map(|(_, &p)| p).collect();
+        let mut copied: Vec<Pos> = cloned.query::<&Pos>().iter().map(|(_, &p)| p).collect();
+        original.sort_by_key(|p| p.0);
+        copied.sort_by_key(|p| p.0);
+
+        assert_eq!(original, copied);
+    }
+
+    #[test]
+    fn try_clone_reports_unregistered_component() {
+        let mut world = World::new_with_clone_registry(CloneRegistry::default());
+        world.spawn((Pos(1),));
+
+        let err = world.try_clone().unwrap_err();
+
+        assert_eq!(err.type_id(), TypeId::of::<Pos>());
+        assert_eq!(err.type_name(), type_name::<Pos>());
+    }
+
+    #[test]
+    fn clone_entity_copies_registered_components_with_a_fresh_id() {
+        let registry = CloneRegistry::default().register::<Pos>();
+        let mut world = World::new_with_clone_registry(registry);
+        let src = world.spawn((Pos(1),));
+
+        let builder = world.clone_entity(src);
+        let cloned = world.spawn(builder.build());
+
+        assert_ne!(cloned, src);
+        assert_eq!(*world.get::<&Pos>(cloned).unwrap(), Pos(1));
+    }
+
+    #[test]
+    fn clone_entity_into_spawns_in_the_destination_world() {
+        let registry = CloneRegistry::default().register::<Pos>();
+        let mut src_world = World::new_with_clone_registry(registry.clone());
+        let src = src_world.spawn((Pos(1),));
+
+        let mut dst_world = World::new_with_clone_registry(registry);
+        let cloned = src_world.clone_entity_into(src, &mut dst_world);
+
+        assert_eq!(*dst_world.get::<&Pos>(cloned).unwrap(), Pos(1));
     }
 }
@@ -0,0 +1,27 @@
+//! Additions to the column-oriented batch-building API used by [`crate::clone`] to avoid
+//! cloning plain-old-data components one element at a time.
+
+use core::ptr;
+
+use crate::{BatchWriter, Component};
+
+impl<'a, T: Component + Copy> BatchWriter<'a, T> {
+    /// Bulk-copies every element of `values` into the remaining unfilled rows of this column
+    /// via a single `memcpy`, without going through `Clone::clone` once per element.
+    ///
+    /// # Safety
+    ///
+    /// `values.len()` must not exceed the number of rows this writer still has unfilled.
+    pub unsafe fn extend_from_slice_copy(&mut self, values: &[T]) {
+        let dst = self.remaining_mut();
+        debug_assert!(values.len() <= dst.len());
+
+        // SAFETY: `dst` is the writer's own uninitialized backing storage for `T`, sized to
+        // hold at least `values.len()` elements (the caller's contract, debug-asserted above),
+        // and `values` is a
+        // `&[T]` the caller can't also be writing through, so the two ranges can't overlap.
+        ptr::copy_nonoverlapping(values.as_ptr(), dst.as_mut_ptr().cast(), values.len());
+
+        self.advance(values.len());
+    }
+}
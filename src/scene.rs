@@ -0,0 +1,226 @@
+//! A registry for saving and loading [`World`](crate::World)s as a portable [`Scene`];
+//! requires the `serde` feature.
+//!
+//! Like [`CloneRegistry`](crate::clone::CloneRegistry), nothing about a component type `T` is
+//! known to a `World` until it is passed to [`SceneRegistry::register`]. A `Scene` is a plain
+//! list of entities, each carrying its original [`Entity`] id and a keyed map of component
+//! key to serialized value, so scenes stay loadable across code changes and are readable as a
+//! diff in any human-readable format such as RON or JSON.
+
+use core::any::TypeId;
+use core::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::collections::BTreeMap;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::{Archetype, Component, Entity, EntityBuilder, World};
+
+/// An opaque registry that holds data that helps a World save/load itself as a [`Scene`].
+#[derive(Clone, Default)]
+pub struct SceneRegistry(Vec<SceneEntry>);
+
+impl SceneRegistry {
+    /// Registers `T` with the registry under `key`, enabling `T` to be saved to and loaded
+    /// from a [`Scene`].
+    ///
+    /// `key` is written into the scene verbatim and is what makes a scene stay loadable as
+    /// code evolves, so pick something stable (e.g. `"position"`), not
+    /// [`core::any::type_name::<T>()`] — that's derived from `T`'s module path and generic
+    /// arguments, both of which are free to change across a refactor with no compile error,
+    /// silently orphaning any saved data still keyed under the old name.
+    pub fn register<T: Component + Serialize + DeserializeOwned>(
+        mut self,
+        key: &'static str,
+    ) -> Self {
+        if let Some(existing) = self.0.iter().find(|item| item.key == key) {
+            assert_eq!(
+                existing.type_id,
+                TypeId::of::<T>(),
+                "scene key `{}` is already registered to a different component type",
+                key
+            );
+        }
+        if !self.0.iter().any(|item| item.type_id == TypeId::of::<T>()) {
+            self.0.push(register::<T>(key));
+        }
+        self
+    }
+}
+
+#[derive(Clone)]
+struct SceneEntry {
+    type_id: TypeId,
+    key: &'static str,
+    save: fn(&'static str, &Archetype, &mut [SavedEntity]) -> (),
+    load: fn(
+        &'static str,
+        &mut EntityBuilder,
+        &BTreeMap<String, serde_value::Value>,
+    ) -> Result<(), SceneLoadError>,
+}
+
+fn register<T: Component + Serialize + DeserializeOwned>(key: &'static str) -> SceneEntry {
+    SceneEntry {
+        type_id: TypeId::of::<T>(),
+        key,
+        save: |key, archetype, entities| {
+            let column = match archetype.get::<T>() {
+                Some(x) => x,
+                None => return,
+            };
+
+            for (saved, value) in entities.iter_mut().zip(column.iter()) {
+                saved.components.insert(
+                    key.to_string(),
+                    serde_value::to_value(value).expect("component must be serializable"),
+                );
+            }
+        },
+        load: |key, builder, components| {
+            let value = match components.get(key) {
+                Some(x) => x,
+                None => return Ok(()),
+            };
+
+            let component = T::deserialize(value.clone()).map_err(|e| SceneLoadError {
+                key,
+                message: e.to_string(),
+            })?;
+            builder.add(component);
+            Ok(())
+        },
+    }
+}
+
+/// Error returned by [`World::load_scene`] when a saved component's value doesn't match the
+/// type registered under its key, which can happen to a scene that was hand-edited or was
+/// saved by a different (possibly buggy) version of the registering code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneLoadError {
+    key: &'static str,
+    message: String,
+}
+
+impl SceneLoadError {
+    /// The registered key of the component that failed to deserialize.
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+}
+
+impl fmt::Display for SceneLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to load component `{}` from scene: {}",
+            self.key, self.message
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SceneLoadError {}
+
+/// A single entity's worth of saved component data, as produced by [`World::save_scene`].
+#[derive(Clone, Serialize, Deserialize)]
+struct SavedEntity {
+    entity: Entity,
+    components: BTreeMap<String, serde_value::Value>,
+}
+
+/// A `World` saved by [`World::save_scene`], ready to be written out in any serde data format
+/// (RON, JSON, ...) and later restored with [`World::load_scene`].
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Scene {
+    entities: Vec<SavedEntity>,
+}
+
+impl World {
+    /// Saves every component registered with `registry` that's present on an entity of this
+    /// `World`, keyed by that component's registered key, into a [`Scene`].
+    ///
+    /// Unregistered component types are silently omitted; unlike `World::try_clone`, saving
+    /// does not fail when a `World` holds a component type the registry doesn't know.
+    pub fn save_scene(&self, registry: &SceneRegistry) -> Scene {
+        let mut entities = Vec::new();
+
+        for archetype in self.archetypes() {
+            let start = entities.len();
+            for &entity in archetype.ids() {
+                entities.push(SavedEntity {
+                    entity,
+                    components: BTreeMap::new(),
+                });
+            }
+            for entry in &registry.0 {
+                (entry.save)(entry.key, archetype, &mut entities[start..]);
+            }
+        }
+
+        Scene { entities }
+    }
+
+    /// Reconstructs a `World` from a [`Scene`] previously produced by [`World::save_scene`].
+    ///
+    /// Entities keep the ids they were saved with, so `Entity` handles embedded in components
+    /// (and saved elsewhere, e.g. by an application's own save data) stay valid across a
+    /// save/load round trip.
+    ///
+    /// Returns [`SceneLoadError`] on the first saved value that doesn't deserialize as its
+    /// registered type, rather than panicking, since a scene is meant to be a hand-editable
+    /// external document and one malformed field shouldn't be fatal to the whole load.
+    pub fn load_scene(scene: &Scene, registry: &SceneRegistry) -> Result<World, SceneLoadError> {
+        let mut world = World::new();
+
+        for saved in &scene.entities {
+            let mut builder = EntityBuilder::new();
+            for entry in &registry.0 {
+                (entry.load)(entry.key, &mut builder, &saved.components)?;
+            }
+            world.spawn_at(saved.entity, builder.build());
+        }
+
+        Ok(world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Pos(i32);
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Name(String);
+
+    #[test]
+    fn scene_round_trips_components_and_ids() {
+        let registry = SceneRegistry::default()
+            .register::<Pos>("position")
+            .register::<Name>("name");
+
+        let mut world = World::new();
+        let a = world.spawn((Pos(1), Name("a".to_string())));
+        let b = world.spawn((Pos(2),));
+
+        let scene = world.save_scene(&registry);
+        let loaded = World::load_scene(&scene, &registry).unwrap();
+
+        assert_eq!(*loaded.get::<&Pos>(a).unwrap(), Pos(1));
+        assert_eq!(*loaded.get::<&Name>(a).unwrap(), Name("a".to_string()));
+        assert_eq!(*loaded.get::<&Pos>(b).unwrap(), Pos(2));
+        assert!(loaded.get::<&Name>(b).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered to a different component type")]
+    fn register_rejects_key_collision_between_types() {
+        SceneRegistry::default()
+            .register::<Pos>("shared")
+            .register::<Name>("shared");
+    }
+}